@@ -0,0 +1,386 @@
+//! Small reusable word-generation library backing the animal-or-not game.
+//!
+//! The game binary (`src/main.rs`) is one consumer of this crate, but
+//! [`Generator`] is general enough to produce memorable multi-word
+//! identifiers/handles (e.g. `fox-owl-bee`) from any word list, independent
+//! of the quiz itself.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// A small seeded pseudo-random number generator (xorshift64).
+///
+/// # Project Context
+/// `random_usize` used to read `SystemTime` nanoseconds fresh on every call.
+/// When several draws happen back-to-back (e.g. picking the first, second,
+/// and third letter of a word in the same frame), the clock barely moves
+/// between calls, so the low bits of consecutive draws were highly
+/// correlated — often identical. `Rng` instead carries mutable state
+/// across calls, seeded once from the clock, so each draw advances an
+/// independent stream position regardless of how close together the calls
+/// happen to be.
+///
+/// # Error Handling
+/// If the clock cannot be read at seed time (before Unix Epoch, time moved
+/// backward), the seed falls back to a fixed nonzero constant rather than
+/// aborting startup.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Seeds a new `Rng` from nanosecond-precision system time.
+    ///
+    /// # Error Handling
+    /// Falls back to a fixed constant seed if system time cannot be read.
+    pub fn seeded() -> Self {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let seed = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_nanos() as u64,
+            Err(_) => 0,
+        };
+
+        Self::from_seed(seed)
+    }
+
+    /// Builds an `Rng` from an explicit seed, substituting a fixed nonzero
+    /// constant if `seed == 0` (xorshift64 is stuck at 0 forever otherwise).
+    pub fn from_seed(seed: u64) -> Self {
+        Rng {
+            state: if seed == 0 { 0x9E3779B97F4A7C15 } else { seed },
+        }
+    }
+
+    /// Advances the generator and returns a pseudo-random number in range
+    /// `[0, max)`.
+    ///
+    /// # Parameters
+    /// - `max`: Upper bound (exclusive) for the random number range
+    ///
+    /// # Returns
+    /// - `usize` in range `[0, max)`, or 0 if `max == 0`
+    ///
+    /// # Edge Cases
+    /// - `max == 0`: Returns 0 (avoids division by zero panic from modulo)
+    /// - `max == 1`: Always returns 0 (only one possible value)
+    pub fn next(&mut self, max: usize) -> usize {
+        // Production catch: Handle zero max case to prevent modulo by zero
+        if max == 0 {
+            return 0;
+        }
+
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+
+        (x as usize) % max
+    }
+}
+
+/// Selects which algorithm a [`Generator`] uses to synthesize a word.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum WordGenMode {
+    /// Independently samples each letter from a per-position pool derived
+    /// from the word list (for three-letter lists, the middle pool is
+    /// restricted to vowels). Fast, but yields many unpronounceable
+    /// consonant clusters.
+    Positional,
+    /// Samples from an order-2 character n-gram model trained on the word
+    /// list, so generated words tend to "look" plausible.
+    Markov,
+}
+
+/// Longest word the Markov generator will produce before giving up, in case
+/// a context is missing its end marker `$` in the training data.
+const MARKOV_MAX_LEN: usize = 12;
+
+/// Trains an order-2 (two letters of context) character n-gram model on
+/// `words`.
+///
+/// Each word is scanned with a start marker `^^` prepended and an end
+/// marker `$` appended, so `(prev2, prev1) -> next` transitions also learn
+/// where words tend to begin and end. Occurrences are stored as repeats in
+/// the `Vec`, so sampling uniformly from it is equivalent to sampling by
+/// frequency.
+fn build_markov_model(words: &[String]) -> HashMap<(char, char), Vec<char>> {
+    let mut model: HashMap<(char, char), Vec<char>> = HashMap::new();
+
+    for word in words {
+        let framed: Vec<char> = ['^', '^']
+            .into_iter()
+            .chain(word.chars())
+            .chain(['$'])
+            .collect();
+
+        for window in framed.windows(3) {
+            model
+                .entry((window[0], window[1]))
+                .or_default()
+                .push(window[2]);
+        }
+    }
+
+    model
+}
+
+/// Generates a word from an order-2 Markov `model`, starting from the
+/// `('^', '^')` context and stopping at the end marker `$` or at `max_len`
+/// characters, whichever comes first.
+fn generate_markov_word(model: &HashMap<(char, char), Vec<char>>, rng: &mut Rng, max_len: usize) -> String {
+    let mut word = String::new();
+    let mut context = ('^', '^');
+
+    while word.len() < max_len {
+        let choices = match model.get(&context) {
+            Some(choices) if !choices.is_empty() => choices,
+            _ => break,
+        };
+
+        let next_char = choices[rng.next(choices.len())];
+        if next_char == '$' {
+            break;
+        }
+
+        word.push(next_char);
+        context = (context.1, next_char);
+    }
+
+    word
+}
+
+/// Derives per-position candidate letter pools from `words`, generalizing
+/// the old fixed three-letter logic to lists of any word length.
+///
+/// Pools are built positionally (pool `i` holds every letter seen at index
+/// `i` across `words`), padding shorter words by simply not contributing to
+/// positions past their end. For three-letter lists this also restores the
+/// original game's hand-picked embellishments: extra plausible first
+/// letters, and forcing the middle position to a vowel (falling back to all
+/// vowels if the loaded words happen to have none in that slot).
+fn position_letter_pools(words: &[String]) -> Vec<Vec<char>> {
+    let word_len = words.iter().map(|w| w.chars().count()).max().unwrap_or(0);
+    let mut pools: Vec<HashSet<char>> = vec![HashSet::new(); word_len];
+
+    for word in words {
+        for (i, c) in word.chars().enumerate() {
+            pools[i].insert(c);
+        }
+    }
+
+    if word_len == 3 {
+        let extra_first = ['t', 'l', 'b', 'p', 's'];
+        pools[0].extend(extra_first);
+
+        let vowels: HashSet<char> = ['a', 'e', 'i', 'o', 'u'].iter().cloned().collect();
+        let mid_vowels: HashSet<char> = pools[1].intersection(&vowels).cloned().collect();
+        pools[1] = if mid_vowels.is_empty() {
+            vowels
+        } else {
+            mid_vowels
+        };
+    }
+
+    pools.into_iter().map(|pool| pool.into_iter().collect()).collect()
+}
+
+/// Where a single [`Generator`] token comes from.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TokenSource {
+    /// Drawn verbatim from the word pool (e.g. an undiscovered animal name).
+    RealWord,
+    /// Synthesized with the given `WordGenMode` letter-pool builder.
+    Synthetic(WordGenMode),
+}
+
+/// Configuration for generating one or more joined word tokens.
+///
+/// Originally `animal_or_not` only ever needed a single synthesized word;
+/// this generalizes that same letter-pool/Markov machinery so it can also
+/// produce multi-word identifiers/handles (e.g. three words joined by `-`)
+/// for use as a small library, not just the single-word terminal game.
+pub struct Generator<'a> {
+    /// How many tokens to join into the final string.
+    token_count: usize,
+    /// String inserted between tokens.
+    separator: String,
+    /// Where each token's letters come from.
+    source: TokenSource,
+    /// Length of each synthetic token. Positional tokens have exactly this
+    /// many letters; Markov tokens have at most this many and may end
+    /// earlier at the model's end marker. `None` uses the full set of
+    /// positional pools, or caps Markov tokens at `MARKOV_MAX_LEN`.
+    /// Ignored for `TokenSource::RealWord`.
+    target_len: Option<usize>,
+    /// Word pool tokens are drawn from or trained on.
+    words: &'a [String],
+}
+
+impl<'a> Generator<'a> {
+    /// Builds a single-token generator over `words`, the same shape
+    /// `animal_or_not` used before this type existed.
+    pub fn new(words: &'a [String], source: TokenSource) -> Self {
+        Generator {
+            token_count: 1,
+            separator: String::from("-"),
+            source,
+            target_len: None,
+            words,
+        }
+    }
+
+    pub fn token_count(mut self, token_count: usize) -> Self {
+        self.token_count = token_count;
+        self
+    }
+
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    pub fn target_len(mut self, target_len: usize) -> Self {
+        self.target_len = Some(target_len);
+        self
+    }
+
+    /// Produces `token_count` tokens from `source`, joined by `separator`.
+    ///
+    /// # Edge Cases
+    /// - Empty `words`: each token is an empty string rather than a panic.
+    pub fn generate(&self, rng: &mut Rng) -> String {
+        (0..self.token_count)
+            .map(|_| self.generate_token(rng))
+            .collect::<Vec<_>>()
+            .join(&self.separator)
+    }
+
+    /// Generates a single token. Returns an empty string if `words` is empty
+    /// rather than panicking, the same "safe default" contract `Rng::next`
+    /// and `generate_markov_word` already follow for a zero/empty input.
+    fn generate_token(&self, rng: &mut Rng) -> String {
+        if self.words.is_empty() {
+            return String::new();
+        }
+
+        match self.source {
+            TokenSource::RealWord => self.words[rng.next(self.words.len())].clone(),
+            TokenSource::Synthetic(WordGenMode::Markov) => {
+                let model = build_markov_model(self.words);
+                let max_len = self.target_len.unwrap_or(MARKOV_MAX_LEN);
+                generate_markov_word(&model, rng, max_len)
+            }
+            TokenSource::Synthetic(WordGenMode::Positional) => {
+                let pools = position_letter_pools(self.words);
+                let len = self.target_len.unwrap_or(pools.len());
+                (0..len)
+                    .map(|i| {
+                        let pool = &pools[i % pools.len()];
+                        pool[rng.next(pool.len())]
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|&s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn rng_next_zero_max_returns_zero() {
+        let mut rng = Rng::from_seed(42);
+        assert_eq!(rng.next(0), 0);
+    }
+
+    #[test]
+    fn rng_from_seed_zero_does_not_get_stuck() {
+        let mut rng = Rng::from_seed(0);
+        // A zero seed would stay 0 forever under plain xorshift64; the
+        // fallback constant must kick in so draws actually vary.
+        assert_ne!(rng.next(1_000_000), rng.next(1_000_000));
+    }
+
+    #[test]
+    fn build_markov_model_learns_transitions_from_start_and_end_markers() {
+        let model = build_markov_model(&words(&["cat"]));
+        assert_eq!(model[&('^', '^')], vec!['c']);
+        assert_eq!(model[&('^', 'c')], vec!['a']);
+        assert_eq!(model[&('c', 'a')], vec!['t']);
+        assert_eq!(model[&('a', 't')], vec!['$']);
+    }
+
+    #[test]
+    fn generate_markov_word_stops_at_max_len_without_panicking() {
+        let model = build_markov_model(&words(&["cat"]));
+        let mut rng = Rng::from_seed(7);
+        let word = generate_markov_word(&model, &mut rng, 2);
+        assert!(word.len() <= 2);
+    }
+
+    #[test]
+    fn generate_markov_word_on_empty_model_is_empty() {
+        let model = build_markov_model(&words(&[]));
+        let mut rng = Rng::from_seed(7);
+        assert_eq!(generate_markov_word(&model, &mut rng, 12), "");
+    }
+
+    #[test]
+    fn position_letter_pools_tracks_letters_per_index() {
+        let pools = position_letter_pools(&words(&["ab", "ac"]));
+        assert_eq!(pools.len(), 2);
+        assert_eq!(pools[0], vec!['a']);
+        let mut second = pools[1].clone();
+        second.sort();
+        assert_eq!(second, vec!['b', 'c']);
+    }
+
+    #[test]
+    fn generator_respects_token_count_and_separator() {
+        let animals = words(&["cat", "dog", "owl"]);
+        let mut rng = Rng::from_seed(123);
+        let result = Generator::new(&animals, TokenSource::RealWord)
+            .token_count(3)
+            .separator(", ")
+            .generate(&mut rng);
+
+        let tokens: Vec<&str> = result.split(", ").collect();
+        assert_eq!(tokens.len(), 3);
+        for token in tokens {
+            assert!(animals.iter().any(|a| a == token));
+        }
+    }
+
+    #[test]
+    fn generator_real_word_on_empty_pool_is_empty_not_panic() {
+        let empty: Vec<String> = Vec::new();
+        let mut rng = Rng::from_seed(1);
+        let result = Generator::new(&empty, TokenSource::RealWord).generate(&mut rng);
+        assert_eq!(result, "");
+    }
+
+    #[test]
+    fn generator_positional_on_empty_pool_is_empty_not_panic() {
+        let empty: Vec<String> = Vec::new();
+        let mut rng = Rng::from_seed(1);
+        let result = Generator::new(&empty, TokenSource::Synthetic(WordGenMode::Positional))
+            .target_len(4)
+            .generate(&mut rng);
+        assert_eq!(result, "");
+    }
+
+    #[test]
+    fn generator_markov_on_empty_pool_is_empty_not_panic() {
+        let empty: Vec<String> = Vec::new();
+        let mut rng = Rng::from_seed(1);
+        let result = Generator::new(&empty, TokenSource::Synthetic(WordGenMode::Markov)).generate(&mut rng);
+        assert_eq!(result, "");
+    }
+}
@@ -1,183 +1,240 @@
 use std::collections::HashSet;
 use std::io::{self, Write};
+use std::path::Path;
+
+use animal_game_rust::{Generator, Rng, TokenSource, WordGenMode};
+
+/// Built-in three-letter animal names, used whenever no wordlist file can be
+/// loaded for the requested category.
+const THREE_LETTER_ANIMAL_NAMES: [&str; 16] = [
+    "pup", "dog", "cat", "rat", "fox", "hen", "bug", "ant", "fly", "pig", "bat", "cow", "hog",
+    "ape", "owl", "bee",
+];
+
+/// Directory that holds one-word-per-line category files, e.g.
+/// `animals/three_letter.txt`, `animals/birds.txt`.
+const WORDLIST_DIR: &str = "animals";
+
+/// Reads a one-word-per-line wordlist file. Blank lines and lines starting
+/// with `#` (comments) are skipped.
+fn load_word_list(path: &Path) -> io::Result<Vec<String>> {
+    let contents = std::fs::read_to_string(path)?;
+
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect())
+}
 
-/// Generates a pseudo-random number in range [0, max) using system time.
-///
-/// # Project Context
-/// Provides minimal randomness. Uses nanosecond-precision
-/// system time as entropy source. Quality is sufficient for casual use where
-/// cryptographic security is not required. User interaction delays between calls
-/// provide adequate entropy distribution.
-///
-/// # Parameters
-/// - `max`: Upper bound (exclusive) for the random number range
+/// Resolves the active word list for the game.
 ///
-/// # Returns
-/// - `usize` in range [0, max), or 0 if an error occurs
-///
-/// # Error Handling
-/// If system time cannot be read (clock before Unix Epoch, time moved backward),
-/// returns 0 as a safe fallback. This allows to continue functioning
-/// even with degraded randomness.
-///
-/// # Edge Cases
-/// - `max == 0`: Returns 0 (avoids division by zero panic from modulo)
-/// - `max == 1`: Always returns 0 (only one possible value)
-/// - System time before 1970: Returns 0
-/// - System clock moved backward: Returns 0
-fn random_usize(max: usize) -> usize {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    // Production catch: Handle zero max case to prevent modulo by zero
-    if max == 0 {
-        return 0;
+/// `category` may be a direct path to a wordlist file, or a bare category
+/// name that is looked up as `animals/<category>.txt`. If `category` is
+/// `None`, no matching file is found, or the file is empty, this falls back
+/// to the built-in `THREE_LETTER_ANIMAL_NAMES`.
+fn resolve_word_list(category: Option<&str>) -> Vec<String> {
+    let candidate_path = category.map(|name| {
+        let direct = Path::new(name);
+        if direct.exists() {
+            direct.to_path_buf()
+        } else {
+            Path::new(WORDLIST_DIR).join(format!("{}.txt", name))
+        }
+    });
+
+    let loaded = candidate_path
+        .and_then(|path| load_word_list(&path).ok())
+        .filter(|words| !words.is_empty());
+
+    if let Some(words) = loaded {
+        return words;
     }
 
-    // Attempt to get system time duration since Unix Epoch
-    let duration_result = SystemTime::now().duration_since(UNIX_EPOCH);
-
-    // Production catch: Handle time errors gracefully
-    let nanos = match duration_result {
-        Ok(duration) => duration.as_nanos(),
-        Err(_) => {
-            // System time is before Unix Epoch or moved backward
-            // Fallback: Return 0 to keep running
-            // Alternative: Could use a counter, hash of process ID, or other fallback
-            return 0;
-        }
-    };
+    THREE_LETTER_ANIMAL_NAMES
+        .iter()
+        .map(|&s| s.to_string())
+        .collect()
+}
+
+/// Environment variable used to pick `WordGenMode` at startup, e.g.
+/// `ANIMAL_GEN_MODE=positional`.
+const GEN_MODE_VAR: &str = "ANIMAL_GEN_MODE";
+
+/// Resolves the active `WordGenMode` from the `ANIMAL_GEN_MODE` environment
+/// variable (`"positional"` or `"markov"`, case-insensitive). Falls back to
+/// `WordGenMode::Markov` if unset or unrecognized.
+fn resolve_gen_mode() -> WordGenMode {
+    match std::env::var(GEN_MODE_VAR) {
+        Ok(value) if value.eq_ignore_ascii_case("positional") => WordGenMode::Positional,
+        _ => WordGenMode::Markov,
+    }
+}
 
-    // Cast to usize and apply modulo to constrain to [0, max)
-    // Note: On 32-bit systems, casting u128 to usize truncates high bits
-    // This still provides adequate randomness for this use case
-    (nanos as usize) % max
+/// Outcome of a single `animal_or_not` round.
+enum Round {
+    /// Every animal in the active set has been discovered; the game is over.
+    Done,
+    /// A word was generated this round, along with whether it is actually a
+    /// real animal from the active set (ground truth for scoring).
+    Word { word: String, is_animal: bool },
 }
 
-fn animal_or_not(animal_done_set: &mut HashSet<String>) -> Result<Option<&'static str>, io::Error> {
+fn animal_or_not(
+    animal_done_set: &mut HashSet<String>,
+    rng: &mut Rng,
+    gen_mode: WordGenMode,
+    active_words: &[String],
+) -> Result<Round, io::Error> {
     // Clear screen with newlines
     println!("{}", "\n".repeat(50));
 
     println!("So far: {:?}\n", animal_done_set);
 
-    // List of three-letter animal names
-    const THREE_LETTER_ANIMAL_NAMES: [&str; 16] = [
-        "pup", "dog", "cat", "rat", "fox", "hen", "bug", "ant", "fly", "pig", "bat", "cow", "hog",
-        "ape", "owl", "bee",
-    ];
-
-    let animals_set: HashSet<_> = THREE_LETTER_ANIMAL_NAMES
-        .iter()
-        .map(|&s| s.to_string())
-        .collect();
+    let animals_set: HashSet<_> = active_words.iter().cloned().collect();
 
     if &animals_set == animal_done_set {
         println!("All Done!");
-        return Ok(Some("All Done!"));
+        return Ok(Round::Done);
     }
 
-    // let mut rng = rand::thread_rng();
-
-    // // 25% chance to return a real animal name
-    // if rng.gen::<f64>() < 0.25 {
-    //     let available_animals: Vec<_> = THREE_LETTER_ANIMAL_NAMES
-    //         .iter()
-    //         .map(|&s| s.to_string())
-    //         .filter(|animal| !animal_done_set.contains(animal))
-    //         .collect();
-
-    //     if !available_animals.is_empty() {
-    //         let word = available_animals[rng.gen_range(0..available_animals.len())].clone();
-    //         animal_done_set.insert(word.clone());
-    //         println!("{}\n", word);
-    //         return Ok(None);
-    //     }
-    // }
-
     // 25% chance to return a real animal name
-    if random_usize(4) == 0 {
-        let available_animals: Vec<_> = THREE_LETTER_ANIMAL_NAMES
+    if rng.next(4) == 0 {
+        let available_animals: Vec<_> = active_words
             .iter()
-            .map(|&s| s.to_string())
-            .filter(|animal| !animal_done_set.contains(animal))
+            .filter(|animal| !animal_done_set.contains(*animal))
+            .cloned()
             .collect();
 
         if !available_animals.is_empty() {
-            let word = available_animals[random_usize(available_animals.len())].clone();
+            let word = Generator::new(&available_animals, TokenSource::RealWord).generate(rng);
             animal_done_set.insert(word.clone());
             println!("{}\n", word);
-            return Ok(None);
+            return Ok(Round::Word {
+                word,
+                is_animal: true,
+            });
         }
     }
 
-    // Create lists of letters from each position
-    let mut first_letters: HashSet<char> = THREE_LETTER_ANIMAL_NAMES
-        .iter()
-        .map(|name| name.chars().next().unwrap())
-        .collect();
-    let mut second_letters: HashSet<char> = THREE_LETTER_ANIMAL_NAMES
-        .iter()
-        .map(|name| name.chars().nth(1).unwrap())
-        .collect();
-    let third_letters: HashSet<char> = THREE_LETTER_ANIMAL_NAMES
-        .iter()
-        .map(|name| name.chars().nth(2).unwrap())
-        .collect();
-
-    // Add extra letters
-    let extra_first = ['t', 'l', 'b', 'p', 's'];
-    first_letters.extend(extra_first);
-
-    // Filter second_letters to only include vowels
-    let vowels: HashSet<char> = ['a', 'e', 'i', 'o', 'u'].iter().cloned().collect();
-    second_letters = second_letters.intersection(&vowels).cloned().collect();
-
-    // If no vowels in second_letters, add them all
-    if second_letters.is_empty() {
-        second_letters = vowels;
-    }
-
-    // Convert sets to vectors for random selection
-    let first_letters: Vec<char> = first_letters.into_iter().collect();
-    let second_letters: Vec<char> = second_letters.into_iter().collect();
-    let third_letters: Vec<char> = third_letters.into_iter().collect();
-
-    // Generate random combination
-    let word = format!(
-        "{}{}{}",
-        first_letters[random_usize(first_letters.len())],
-        second_letters[random_usize(second_letters.len())],
-        third_letters[random_usize(third_letters.len())]
-    );
+    let word = Generator::new(active_words, TokenSource::Synthetic(gen_mode)).generate(rng);
 
     // Check if it's a real animal name and add to set if it is
-    if THREE_LETTER_ANIMAL_NAMES.contains(&word.as_str()) {
+    let is_animal = active_words.contains(&word);
+    if is_animal {
         animal_done_set.insert(word.clone());
     }
 
     println!("{}\n", word);
 
-    Ok(None)
+    Ok(Round::Word { word, is_animal })
 }
 
-fn main() -> io::Result<()> {
-    let mut done_set = HashSet::new();
+/// Tracks running quiz performance across rounds.
+#[derive(Default)]
+struct Score {
+    correct: usize,
+    wrong: usize,
+    current_streak: usize,
+    best_streak: usize,
+}
 
-    loop {
-        match animal_or_not(&mut done_set)? {
-            Some("All Done!") => break,
-            _ => {
-                print!("Press Enter to continue (or type 'exit' to quit): ");
-                io::stdout().flush()?;
+impl Score {
+    /// Records one round's outcome and updates the running streak.
+    fn record(&mut self, was_correct: bool) {
+        if was_correct {
+            self.correct += 1;
+            self.current_streak += 1;
+            self.best_streak = self.best_streak.max(self.current_streak);
+        } else {
+            self.wrong += 1;
+            self.current_streak = 0;
+        }
+    }
+
+    /// One-line score readout shown after every round.
+    fn score_line(&self) -> String {
+        format!(
+            "Correct: {} | Wrong: {} | Streak: {} (best: {})",
+            self.correct, self.wrong, self.current_streak, self.best_streak
+        )
+    }
+}
 
-                let mut input = String::new();
-                io::stdin().read_line(&mut input)?;
+fn main() -> io::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let first_arg = args.next();
+
+    // `handle [category]`: print one memorable multi-word handle and exit,
+    // instead of playing the quiz. Demonstrates using `Generator` as a
+    // small library for producing identifiers, e.g. `fox-owl-bee`.
+    if first_arg.as_deref() == Some("handle") {
+        let active_words = resolve_word_list(args.next().as_deref());
+        let mut rng = Rng::seeded();
+        let handle = Generator::new(&active_words, TokenSource::Synthetic(resolve_gen_mode()))
+            .token_count(3)
+            .separator("-")
+            .target_len(4)
+            .generate(&mut rng);
+
+        println!("{}", handle);
+        return Ok(());
+    }
+
+    // Optional first CLI argument: a path to a wordlist file, or a bare
+    // category name resolved under `animals/<name>.txt`. Falls back to the
+    // built-in three-letter animal list if not given or not found.
+    let active_words = resolve_word_list(first_arg.as_deref());
 
-                if input.trim() == "exit" {
-                    println!("OK!");
-                    break;
+    let mut done_set = HashSet::new();
+    let mut rng = Rng::seeded();
+    let mut score = Score::default();
+
+    // Set `ANIMAL_GEN_MODE=positional` to fall back to the old
+    // independent-letter-pool generator; defaults to the Markov model.
+    let gen_mode = resolve_gen_mode();
+
+    'game: loop {
+        match animal_or_not(&mut done_set, &mut rng, gen_mode, &active_words)? {
+            Round::Done => break,
+            Round::Word { word, is_animal } => {
+                // Re-prompt on anything that isn't 'a', 'n', or 'exit' instead
+                // of silently scoring a typo or empty Enter as an 'n' guess.
+                let guessed_animal = loop {
+                    print!("Animal or not? [a/n] (or 'exit'): ");
+                    io::stdout().flush()?;
+
+                    let mut input = String::new();
+                    io::stdin().read_line(&mut input)?;
+                    let input = input.trim();
+
+                    if input.eq_ignore_ascii_case("exit") {
+                        println!("OK!");
+                        break 'game;
+                    } else if input.eq_ignore_ascii_case("a") {
+                        break true;
+                    } else if input.eq_ignore_ascii_case("n") {
+                        break false;
+                    } else {
+                        println!("Please type 'a', 'n', or 'exit'.\n");
+                    }
+                };
+
+                let was_correct = guessed_animal == is_animal;
+                score.record(was_correct);
+
+                if was_correct {
+                    println!("Correct! \"{}\" {}.", word, if is_animal { "is an animal" } else { "is not an animal" });
+                } else {
+                    println!("Wrong! \"{}\" {}.", word, if is_animal { "is an animal" } else { "is not an animal" });
                 }
+                println!("{}\n", score.score_line());
             }
         }
     }
 
+    println!("\nFinal score — {}", score.score_line());
+
     Ok(())
 }